@@ -4,8 +4,14 @@ use bevy::{
     tasks::IoTaskPool,
     utils::HashSet,
 };
+use serde::de::DeserializeSeed;
 use space_shared::{EditorPrefabPath, PrefabMarker, PrefabMemoryCache};
-use std::{any::TypeId, fs, io::Write};
+use std::{
+    any::TypeId,
+    fs,
+    io::Write,
+    sync::{Arc, Mutex},
+};
 
 use crate::prelude::{EditorRegistry, EditorRegistryExt, SceneAutoChild};
 
@@ -32,13 +38,57 @@ impl MapEntities for ChildrenPrefab {
     }
 }
 
+#[derive(Reflect, Default, Component, Clone, Copy)]
+#[reflect(Component)]
+/// Marker for entities that hold runtime state rather than static blueprint
+/// data. When [`SaveConfig::save_mode`] is [`SaveMode::DynamicOnly`], only
+/// entities carrying this component are written to the saved prefab.
+pub struct Dynamic;
+
+/// Fired right before a prefab save begins writing out to its destination.
+#[derive(Event, Debug, Clone)]
+pub struct SaveStartedEvent {
+    pub path: EditorPrefabPath,
+}
+
+/// Fired once a prefab save has actually finished, which for
+/// [`EditorPrefabPath::File`] may be after the frame that called
+/// [`serialize_scene`] has already ended, since the write happens on the
+/// `IoTaskPool`.
+#[derive(Event, Debug, Clone)]
+pub struct SaveFinishedEvent {
+    pub path: EditorPrefabPath,
+    pub result: Result<(), String>,
+}
+
+/// Queue that the detached save task pushes its result into, drained every
+/// frame by [`relay_save_finished_events`] and turned into a
+/// [`SaveFinishedEvent`]. A channel/mutex hop is needed here because the
+/// write happens off the ECS schedule on `IoTaskPool`.
+#[derive(Resource, Clone, Default)]
+struct SaveResultQueue(Arc<Mutex<Vec<SaveFinishedEvent>>>);
+
+fn relay_save_finished_events(
+    queue: Res<SaveResultQueue>,
+    mut events: EventWriter<SaveFinishedEvent>,
+) {
+    let mut queue = queue.0.lock().expect("save result queue mutex poisoned");
+    events.send_batch(queue.drain(..));
+}
+
 struct SaveResourcesPrefabPlugin;
 
 impl Plugin for SaveResourcesPrefabPlugin {
     fn build(&self, app: &mut App) {
         app.editor_registry::<ChildrenPrefab>();
+        app.editor_registry::<Dynamic>();
 
-        app.init_resource::<SaveConfig>().add_state::<SaveState>();
+        app.init_resource::<SaveConfig>()
+            .init_resource::<SaveResultQueue>()
+            .add_state::<SaveState>()
+            .add_event::<SaveStartedEvent>()
+            .add_event::<SaveFinishedEvent>()
+            .add_systems(Update, relay_save_finished_events);
     }
 }
 
@@ -64,9 +114,62 @@ impl Plugin for SavePrefabPlugin {
 
 /// This struct determine path to save prefab
 #[cfg(not(tarpaulin_include))]
-#[derive(Resource, Clone, Default)]
+#[derive(Resource, Clone)]
 pub struct SaveConfig {
     pub path: Option<EditorPrefabPath>,
+    /// Allowlist of resource types that should be extracted into the saved
+    /// prefab alongside entities. Empty by default (deny-all); opt in with
+    /// [`SaveRegistryExt::editor_save_resource`].
+    pub resource_filter: SceneFilter,
+    /// Which entities are written to the saved prefab.
+    pub save_mode: SaveMode,
+}
+
+impl Default for SaveConfig {
+    fn default() -> Self {
+        Self {
+            path: None,
+            resource_filter: SceneFilter::Allowlist(HashSet::default()),
+            save_mode: SaveMode::default(),
+        }
+    }
+}
+
+/// Selects which entities [`serialize_scene`] writes to the saved prefab.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub enum SaveMode {
+    /// Save every entity with [`PrefabMarker`] (the current/default behavior).
+    #[default]
+    Everything,
+    /// Save only entities additionally carrying the [`Dynamic`] marker. Lets
+    /// a large static blueprint be loaded once and saves shrink down to just
+    /// the runtime state that changed.
+    DynamicOnly,
+}
+
+/// Opt a resource type into [`SaveConfig::resource_filter`] so it is
+/// extracted alongside entities when a prefab is saved.
+///
+/// Resources are deny-all by default (mirroring how entity components must
+/// be allowlisted via [`EditorRegistryExt::editor_registry`]), so world-level
+/// state like ambient light settings or custom game config must opt in here
+/// before `serialize_scene` will persist it.
+pub trait SaveRegistryExt {
+    fn editor_save_resource<T: Reflect + Resource + bevy::reflect::GetTypeRegistration>(
+        &mut self,
+    ) -> &mut Self;
+}
+
+impl SaveRegistryExt for App {
+    fn editor_save_resource<T: Reflect + Resource + bevy::reflect::GetTypeRegistration>(
+        &mut self,
+    ) -> &mut Self {
+        // Resources missing from the type registry are skipped by `extract_resources`.
+        self.register_type::<T>();
+        let mut config = self.world.resource_mut::<SaveConfig>();
+        config.resource_filter = std::mem::take(&mut config.resource_filter).allow::<T>();
+        self
+    }
 }
 
 /// State system using to enable slow logic of saving
@@ -81,11 +184,15 @@ pub enum SaveState {
 fn prepare_children(
     mut commands: Commands,
     query: Query<(Entity, &Children), (With<PrefabMarker>, Without<SceneAutoChild>)>,
+    serializable: Query<(), (With<PrefabMarker>, Without<SceneAutoChild>)>,
 ) {
     for (entity, children) in query.iter() {
-        commands
-            .entity(entity)
-            .insert(ChildrenPrefab::from_children(children));
+        let children = children
+            .iter()
+            .copied()
+            .filter(|child| serializable.contains(*child))
+            .collect::<Vec<_>>();
+        commands.entity(entity).insert(ChildrenPrefab(children));
     }
 }
 
@@ -101,7 +208,13 @@ pub fn serialize_scene(world: &mut World) {
 
     let mut prefab_query =
         world.query_filtered::<Entity, (With<PrefabMarker>, Without<SceneAutoChild>)>();
-    let entities = prefab_query.iter(world).collect::<Vec<_>>();
+    let entities = prefab_query
+        .iter(world)
+        .filter(|entity| match config.save_mode {
+            SaveMode::Everything => true,
+            SaveMode::DynamicOnly => world.get::<Dynamic>(*entity).is_some(),
+        })
+        .collect::<Vec<_>>();
 
     if entities.is_empty() {
         #[cfg(feature = "editor")]
@@ -125,7 +238,9 @@ pub fn serialize_scene(world: &mut World) {
         .with_filter(SceneFilter::Allowlist(HashSet::from_iter(
             allow_types.iter().cloned(),
         )))
-        .extract_entities(entities.iter().copied());
+        .extract_entities(entities.iter().copied())
+        .with_resource_filter(config.resource_filter.clone())
+        .extract_resources();
     let scene = builder.build();
 
     let res = scene.serialize_ron(world.resource::<AppTypeRegistry>());
@@ -134,25 +249,47 @@ pub fn serialize_scene(world: &mut World) {
         // Write the scene RON data to file
         let path = config.path;
         if let Some(path) = path {
+            world.send_event(SaveStartedEvent { path: path.clone() });
+
             match path {
-                EditorPrefabPath::File(path) => {
+                EditorPrefabPath::File(ref file_path) => {
+                    let file_path = file_path.clone();
+                    let result_queue = world.resource::<SaveResultQueue>().0.clone();
                     IoTaskPool::get()
                         .spawn(async move {
-                            fs::OpenOptions::new()
+                            let result = fs::OpenOptions::new()
                                 .create(true)
                                 .append(false)
                                 .write(true)
-                                .open(&path)
+                                .open(&file_path)
                                 .and_then(|mut file| file.write(str.as_bytes()))
-                                .inspect_err(|e| error!("Error while writing scene to file: {e}"))
-                                .expect("Error while writing scene to file");
-                            info!("Saved prefab to file {}", path);
+                                .map(|_| ())
+                                .map_err(|e| {
+                                    error!("Error while writing scene to file: {e}");
+                                    e.to_string()
+                                });
+
+                            if result.is_ok() {
+                                info!("Saved prefab to file {}", file_path);
+                            }
+
+                            result_queue
+                                .lock()
+                                .expect("save result queue mutex poisoned")
+                                .push(SaveFinishedEvent {
+                                    path: EditorPrefabPath::File(file_path),
+                                    result,
+                                });
                         })
                         .detach();
                 }
                 EditorPrefabPath::MemoryCache => {
                     let handle = world.resource_mut::<Assets<DynamicScene>>().add(scene);
                     world.resource_mut::<PrefabMemoryCache>().scene = Some(handle);
+                    world.send_event(SaveFinishedEvent {
+                        path: EditorPrefabPath::MemoryCache,
+                        result: Ok(()),
+                    });
                 }
             }
         }
@@ -173,16 +310,219 @@ pub fn serialize_scene(world: &mut World) {
         .set(SaveState::Idle);
 }
 
+/// Minimum time between two reloads. The first change seen after a quiet
+/// period reloads at once; a change seen within this window of the previous
+/// reload is picked up once the window has passed, so a burst of writes from
+/// an external tool (or another editor instance) reloads at most once per
+/// window.
+const WATCH_DEBOUNCE: std::time::Duration = std::time::Duration::from_millis(500);
+
+/// Opt-in toggle for [`WatchPrefabPlugin`]. Off by default so headless/CI
+/// runs never pay for filesystem polling.
+#[derive(Resource, Clone, Default)]
+pub struct WatchConfig {
+    pub enabled: bool,
+}
+
+/// Tracks the prefab file currently being watched and when it was last seen
+/// to change, so [`watch_and_reload_prefab`] can debounce reloads.
+#[derive(Resource, Clone, Default)]
+struct WatchedPrefabState {
+    path: Option<String>,
+    last_modified: Option<std::time::SystemTime>,
+    last_reload: Option<std::time::Instant>,
+}
+
+/// Watches the file backing [`SaveConfig::path`] and re-instantiates it into
+/// the world whenever it changes on disk, so an external edit (or a second
+/// editor instance) shows up without a manual reload. Opt in via
+/// [`WatchConfig::enabled`].
+pub struct WatchPrefabPlugin;
+
+impl Plugin for WatchPrefabPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<WatchConfig>()
+            .init_resource::<WatchedPrefabState>()
+            .add_systems(Update, watch_and_reload_prefab);
+    }
+}
+
+fn watch_and_reload_prefab(world: &mut World) {
+    if !world.resource::<WatchConfig>().enabled {
+        return;
+    }
+
+    let path = match &world.resource::<SaveConfig>().path {
+        Some(EditorPrefabPath::File(path)) => path.clone(),
+        _ => return,
+    };
+
+    let Ok(modified) = fs::metadata(&path).and_then(|meta| meta.modified()) else {
+        return;
+    };
+
+    {
+        let mut state = world.resource_mut::<WatchedPrefabState>();
+        if state.path.as_deref() != Some(path.as_str()) {
+            // Watching a new path for the first time: record its current
+            // mtime as the baseline instead of treating it as a change.
+            state.path = Some(path.clone());
+            state.last_modified = Some(modified);
+            return;
+        }
+
+        if state.last_modified == Some(modified) {
+            return;
+        }
+
+        if let Some(last_reload) = state.last_reload {
+            if last_reload.elapsed() < WATCH_DEBOUNCE {
+                return;
+            }
+        }
+
+        state.last_modified = Some(modified);
+        state.last_reload = Some(std::time::Instant::now());
+    }
+
+    let Ok(contents) = fs::read_to_string(&path) else {
+        error!("Failed to read watched prefab file {path}");
+        return;
+    };
+
+    let scene = {
+        let type_registry = world.resource::<AppTypeRegistry>().0.clone();
+        let type_registry = type_registry.read();
+        let scene_deserializer = bevy::scene::serde::SceneDeserializer {
+            type_registry: &type_registry,
+        };
+        let mut ron_deserializer = match ron::de::Deserializer::from_str(&contents) {
+            Ok(de) => de,
+            Err(e) => {
+                error!("Failed to parse watched prefab file {path}: {e}");
+                return;
+            }
+        };
+        match scene_deserializer.deserialize(&mut ron_deserializer) {
+            Ok(scene) => scene,
+            Err(e) => {
+                error!("Failed to deserialize watched prefab file {path}: {e}");
+                return;
+            }
+        }
+    };
+
+    let mut stale_entities = world.query_filtered::<Entity, With<PrefabMarker>>();
+    let stale_entities = stale_entities.iter(world).collect::<Vec<_>>();
+    for entity in stale_entities {
+        bevy::hierarchy::despawn_with_children_recursive(world, entity);
+    }
+
+    let mut entity_map = bevy::ecs::entity::EntityMap::default();
+    if let Err(e) = scene.write_to_world(world, &mut entity_map) {
+        error!("Failed to reload watched prefab file {path}: {e}");
+    } else {
+        info!("Reloaded prefab from file {path}");
+    }
+}
+
+/// Command that duplicates `source` onto `destination`, reflect-cloning
+/// every component allowlisted by the [`EditorRegistry`] (the same set
+/// [`serialize_scene`] would persist) and recursively duplicating its
+/// `Children` hierarchy under fresh entity IDs. Only children that carry
+/// [`PrefabMarker`] and lack [`SceneAutoChild`] are duplicated; other
+/// children are skipped along with their descendants.
+pub struct CloneEntity {
+    pub source: Entity,
+    pub destination: Entity,
+}
+
+impl Command for CloneEntity {
+    fn apply(self, world: &mut World) {
+        clone_entity_components(world, self.source, self.destination);
+        clone_entity_children(world, self.source, self.destination);
+    }
+}
+
+fn clone_entity_components(world: &mut World, source: Entity, destination: Entity) {
+    let registry = world.resource::<EditorRegistry>().clone();
+    let allow_types: HashSet<TypeId> = registry
+        .registry
+        .read()
+        .iter()
+        .map(|a| a.type_id())
+        .collect();
+
+    let type_registry = world.resource::<AppTypeRegistry>().0.clone();
+    let type_registry = type_registry.read();
+
+    let reflect_components = type_registry
+        .iter()
+        .filter(|registration| allow_types.contains(&registration.type_id()))
+        .filter_map(|registration| registration.data::<ReflectComponent>())
+        .cloned()
+        .collect::<Vec<_>>();
+
+    for reflect_component in reflect_components {
+        let Some(source_value) = reflect_component
+            .reflect(world.entity(source))
+            .map(|component| component.clone_value())
+        else {
+            continue;
+        };
+
+        reflect_component.apply_or_insert(
+            &mut world.entity_mut(destination),
+            source_value.as_ref(),
+            &type_registry,
+        );
+    }
+}
+
+fn clone_entity_children(world: &mut World, source: Entity, destination: Entity) {
+    let Some(children) = world.get::<Children>(source).map(|children| children.to_vec()) else {
+        return;
+    };
+
+    let mut serializable = world.query_filtered::<Entity, (With<PrefabMarker>, Without<SceneAutoChild>)>();
+    let children = children
+        .into_iter()
+        .filter(|child| serializable.get(world, *child).is_ok())
+        .collect::<Vec<_>>();
+
+    for child in children {
+        let new_child = world.spawn_empty().id();
+        clone_entity_components(world, child, new_child);
+        clone_entity_children(world, child, new_child);
+        world.entity_mut(destination).add_child(new_child);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::prelude::*;
 
+    /// Polls `app` until a [`SaveFinishedEvent`] has been relayed from the
+    /// detached save task, instead of guessing with a fixed sleep.
+    fn wait_for_save_finished(app: &mut App) -> SaveFinishedEvent {
+        for _ in 0..100 {
+            app.update();
+            let events = app.world.resource::<Events<SaveFinishedEvent>>();
+            if let Some(event) = events.get_reader().read(events).next() {
+                return event.clone();
+            }
+            std::thread::sleep(std::time::Duration::from_millis(10));
+        }
+        panic!("did not receive SaveFinishedEvent in time");
+    }
+
     #[test]
-    fn flaky_save_to_file() {
+    fn save_to_file() {
         let file = "test.ron";
         let save_config = SaveConfig {
             path: Some(EditorPrefabPath::File(String::from(file))),
+            ..Default::default()
         };
         let mut app = App::new();
         app.add_plugins((
@@ -208,13 +548,9 @@ mod tests {
 
         serialize_scene(&mut app.world);
 
-        // Delay for 0.2 second for IOTaskPool to finish
-        std::thread::sleep(std::time::Duration::from_secs_f32(0.2));
-
-        assert!(
-            std::fs::metadata(format!("./{}", file)).is_ok(),
-            "Flaky Test: File not found"
-        );
+        let event = wait_for_save_finished(&mut app);
+        assert!(event.result.is_ok());
+        assert!(matches!(event.path, EditorPrefabPath::File(p) if p == file));
 
         let contents = std::fs::read_to_string(file).unwrap();
 
@@ -222,10 +558,44 @@ mod tests {
         assert!(contents.contains("space_shared::PrefabMarker"));
     }
 
+    #[test]
+    fn save_to_file_reports_io_error() {
+        let file = "test_nonexistent_dir/test.ron";
+        let save_config = SaveConfig {
+            path: Some(EditorPrefabPath::File(String::from(file))),
+            ..Default::default()
+        };
+        let mut app = App::new();
+        app.add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            ImagePlugin::default(),
+            bevy::scene::ScenePlugin,
+            EditorRegistryPlugin {},
+            SaveResourcesPrefabPlugin {},
+        ))
+        .insert_resource(save_config)
+        .init_resource::<PrefabMemoryCache>()
+        .editor_registry::<Name>()
+        .editor_registry::<PrefabMarker>()
+        .add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(PrefabMarker).insert(Name::new("my_name"));
+        });
+
+        app.update();
+
+        serialize_scene(&mut app.world);
+
+        let event = wait_for_save_finished(&mut app);
+        assert!(event.result.is_err());
+        assert!(matches!(event.path, EditorPrefabPath::File(ref p) if p == file));
+    }
+
     #[test]
     fn save_to_memory() {
         let save_config = SaveConfig {
             path: Some(EditorPrefabPath::MemoryCache),
+            ..Default::default()
         };
         let mut app = App::new();
         app.add_plugins((
@@ -255,6 +625,116 @@ mod tests {
             .resource_mut::<PrefabMemoryCache>()
             .scene
             .is_some());
+
+        let events = app.world.resource::<Events<SaveFinishedEvent>>();
+        let mut reader = events.get_reader();
+        let event = reader.read(events).next().expect("no SaveFinishedEvent");
+        assert!(event.result.is_ok());
+        assert!(matches!(event.path, EditorPrefabPath::MemoryCache));
+    }
+
+    #[test]
+    fn save_started_event_fires_before_write_completes() {
+        let file = "test_started_before_finished.ron";
+        let save_config = SaveConfig {
+            path: Some(EditorPrefabPath::File(String::from(file))),
+            ..Default::default()
+        };
+        let mut app = App::new();
+        app.add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            ImagePlugin::default(),
+            bevy::scene::ScenePlugin,
+            EditorRegistryPlugin {},
+            SaveResourcesPrefabPlugin {},
+        ))
+        .insert_resource(save_config)
+        .init_resource::<PrefabMemoryCache>()
+        .editor_registry::<PrefabMarker>()
+        .add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(PrefabMarker);
+        });
+
+        app.update();
+
+        serialize_scene(&mut app.world);
+
+        // The write is dispatched onto IoTaskPool and only reported back
+        // into the world once the relay system runs on a later Update tick,
+        // so SaveStartedEvent must already be visible while
+        // SaveFinishedEvent is not, regardless of how fast the task itself
+        // actually completes on its background thread.
+        let started_events = app.world.resource::<Events<SaveStartedEvent>>();
+        let mut started_reader = started_events.get_reader();
+        let started_event = started_reader
+            .read(started_events)
+            .next()
+            .expect("no SaveStartedEvent");
+        assert!(matches!(started_event.path, EditorPrefabPath::File(ref p) if p == file));
+
+        let finished_events = app.world.resource::<Events<SaveFinishedEvent>>();
+        assert!(
+            finished_events.get_reader().read(finished_events).next().is_none(),
+            "SaveFinishedEvent should not be visible before the write is reported back in"
+        );
+
+        let finished_event = wait_for_save_finished(&mut app);
+        assert!(finished_event.result.is_ok());
+
+        std::fs::remove_file(file).ok();
+    }
+
+    fn run_save_mode_test(file: &str, save_mode: SaveMode) -> String {
+        let save_config = SaveConfig {
+            path: Some(EditorPrefabPath::File(String::from(file))),
+            save_mode,
+            ..Default::default()
+        };
+        let mut app = App::new();
+        app.add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            ImagePlugin::default(),
+            bevy::scene::ScenePlugin,
+            EditorRegistryPlugin {},
+            SaveResourcesPrefabPlugin {},
+        ))
+        .insert_resource(save_config)
+        .init_resource::<PrefabMemoryCache>()
+        .editor_registry::<Name>()
+        .editor_registry::<PrefabMarker>()
+        .add_systems(Startup, |mut commands: Commands| {
+            commands
+                .spawn((PrefabMarker, Dynamic))
+                .insert(Name::new("dynamic_entity"));
+            commands.spawn(PrefabMarker).insert(Name::new("static_entity"));
+        });
+
+        app.update();
+
+        serialize_scene(&mut app.world);
+
+        let event = wait_for_save_finished(&mut app);
+        assert!(event.result.is_ok());
+
+        let contents = std::fs::read_to_string(file).unwrap();
+        std::fs::remove_file(file).ok();
+        contents
+    }
+
+    #[test]
+    fn everything_mode_saves_all_marked_entities() {
+        let contents = run_save_mode_test("test_everything_mode.ron", SaveMode::Everything);
+        assert!(contents.contains("dynamic_entity"));
+        assert!(contents.contains("static_entity"));
+    }
+
+    #[test]
+    fn dynamic_only_mode_saves_only_dynamic_entities() {
+        let contents = run_save_mode_test("test_dynamic_only_mode.ron", SaveMode::DynamicOnly);
+        assert!(contents.contains("dynamic_entity"));
+        assert!(!contents.contains("static_entity"));
     }
 
     #[test]
@@ -312,6 +792,7 @@ mod tests {
     fn attempts_to_serialize_empty_scene() {
         let save_config = SaveConfig {
             path: Some(EditorPrefabPath::MemoryCache),
+            ..Default::default()
         };
         let mut app = App::new();
         app.add_plugins((
@@ -358,4 +839,227 @@ mod tests {
         let mut query = app.world.query_filtered::<Entity, With<ChildrenPrefab>>();
         assert_eq!(query.iter(&app.world).count(), 1);
     }
+
+    #[test]
+    fn prepare_children_prunes_unserializable_children() {
+        let mut app = App::new();
+        app.add_systems(Startup, |mut commands: Commands| {
+            let marked_child = commands.spawn(PrefabMarker).id();
+            let unmarked_child = commands.spawn_empty().id();
+            let auto_child = commands.spawn((PrefabMarker, SceneAutoChild)).id();
+
+            commands
+                .spawn(PrefabMarker)
+                .add_child(marked_child)
+                .add_child(unmarked_child)
+                .add_child(auto_child);
+        })
+        .add_systems(Update, prepare_children);
+        app.update();
+
+        let mut query = app.world.query::<&ChildrenPrefab>();
+        let children_prefab = query.single(&app.world);
+
+        assert_eq!(children_prefab.0.len(), 1);
+    }
+
+    #[derive(Resource, Reflect, Default, Clone)]
+    #[reflect(Resource)]
+    struct TestGameConfig {
+        difficulty: u32,
+    }
+
+    #[test]
+    fn serializes_registered_resources() {
+        let file = "test_resources.ron";
+        let save_config = SaveConfig {
+            path: Some(EditorPrefabPath::File(String::from(file))),
+            ..Default::default()
+        };
+        let mut app = App::new();
+        app.add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            ImagePlugin::default(),
+            bevy::scene::ScenePlugin,
+            EditorRegistryPlugin {},
+            SaveResourcesPrefabPlugin {},
+        ))
+        .insert_resource(save_config)
+        .insert_resource(TestGameConfig { difficulty: 7 })
+        .init_resource::<PrefabMemoryCache>()
+        .editor_registry::<Name>()
+        .editor_registry::<PrefabMarker>()
+        .editor_save_resource::<TestGameConfig>()
+        .add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(PrefabMarker).insert(Name::new("my_name"));
+        });
+
+        app.update();
+
+        serialize_scene(&mut app.world);
+
+        let event = wait_for_save_finished(&mut app);
+        assert!(event.result.is_ok());
+
+        let contents = std::fs::read_to_string(file).unwrap();
+
+        assert!(contents.contains("TestGameConfig"));
+        assert!(contents.contains("difficulty"));
+
+        std::fs::remove_file(file).ok();
+    }
+
+    #[test]
+    fn reloads_prefab_when_watched_file_changes() {
+        let file = "test_watch.ron";
+
+        // Produce a valid prefab RON file on disk to reload from.
+        let mut writer_app = App::new();
+        writer_app
+            .add_plugins((
+                MinimalPlugins,
+                AssetPlugin::default(),
+                ImagePlugin::default(),
+                bevy::scene::ScenePlugin,
+                EditorRegistryPlugin {},
+                SaveResourcesPrefabPlugin {},
+            ))
+            .insert_resource(SaveConfig {
+                path: Some(EditorPrefabPath::File(String::from(file))),
+                ..Default::default()
+            })
+            .init_resource::<PrefabMemoryCache>()
+            .editor_registry::<Name>()
+            .editor_registry::<PrefabMarker>()
+            .add_systems(Startup, |mut commands: Commands| {
+                commands.spawn(PrefabMarker).insert(Name::new("from_disk"));
+            });
+        writer_app.update();
+        serialize_scene(&mut writer_app.world);
+        wait_for_save_finished(&mut writer_app);
+
+        // Load it into a fresh world via the watcher.
+        let mut app = App::new();
+        app.add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            ImagePlugin::default(),
+            bevy::scene::ScenePlugin,
+            EditorRegistryPlugin {},
+            SaveResourcesPrefabPlugin {},
+            WatchPrefabPlugin {},
+        ))
+        .insert_resource(SaveConfig {
+            path: Some(EditorPrefabPath::File(String::from(file))),
+            ..Default::default()
+        })
+        .insert_resource(WatchConfig { enabled: true })
+        .editor_registry::<Name>()
+        .editor_registry::<PrefabMarker>();
+
+        // Call the exclusive system directly (rather than through
+        // `app.update()`, which would also run it via `WatchPrefabPlugin`'s
+        // `Update` schedule) so this really is the first poll of a
+        // newly-seen path, and only records a baseline mtime.
+        watch_and_reload_prefab(&mut app.world);
+        assert_eq!(
+            app.world
+                .query_filtered::<Entity, With<PrefabMarker>>()
+                .iter(&app.world)
+                .count(),
+            0
+        );
+
+        // Force the recorded mtime backwards so the next poll sees a "change".
+        app.world
+            .resource_mut::<WatchedPrefabState>()
+            .last_modified = Some(std::time::SystemTime::UNIX_EPOCH);
+
+        watch_and_reload_prefab(&mut app.world);
+
+        let mut query = app.world.query_filtered::<&Name, With<PrefabMarker>>();
+        let names = query.iter(&app.world).collect::<Vec<_>>();
+        assert_eq!(names.len(), 1);
+        assert_eq!(names[0].as_str(), "from_disk");
+
+        std::fs::remove_file(file).ok();
+    }
+
+    #[test]
+    fn clone_entity_duplicates_components_and_children() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, EditorRegistryPlugin {}))
+            .editor_registry::<Name>()
+            .editor_registry::<PrefabMarker>();
+
+        let child = app
+            .world
+            .spawn((PrefabMarker, Name::new("child")))
+            .id();
+        let source = app
+            .world
+            .spawn((PrefabMarker, Name::new("source")))
+            .add_child(child)
+            .id();
+        let destination = app.world.spawn_empty().id();
+
+        CloneEntity {
+            source,
+            destination,
+        }
+        .apply(&mut app.world);
+
+        assert_eq!(
+            app.world.get::<Name>(destination).map(Name::as_str),
+            Some("source")
+        );
+        assert!(app.world.get::<PrefabMarker>(destination).is_some());
+
+        let cloned_children = app
+            .world
+            .get::<Children>(destination)
+            .expect("destination should have a cloned child");
+        assert_eq!(cloned_children.len(), 1);
+
+        let cloned_child = cloned_children[0];
+        assert_ne!(cloned_child, child);
+        assert_eq!(
+            app.world.get::<Name>(cloned_child).map(Name::as_str),
+            Some("child")
+        );
+        assert!(app.world.get::<PrefabMarker>(cloned_child).is_some());
+    }
+
+    #[test]
+    fn clone_entity_does_not_duplicate_scene_auto_children() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, EditorRegistryPlugin {}))
+            .editor_registry::<Name>()
+            .editor_registry::<PrefabMarker>();
+
+        let auto_child = app
+            .world
+            .spawn((PrefabMarker, SceneAutoChild, Name::new("auto_child")))
+            .id();
+        let unmarked_child = app.world.spawn_empty().id();
+        let source = app
+            .world
+            .spawn((PrefabMarker, Name::new("source")))
+            .add_child(auto_child)
+            .add_child(unmarked_child)
+            .id();
+        let destination = app.world.spawn_empty().id();
+
+        CloneEntity {
+            source,
+            destination,
+        }
+        .apply(&mut app.world);
+
+        assert!(
+            app.world.get::<Children>(destination).is_none(),
+            "SceneAutoChild and unmarked children should not be duplicated onto destination"
+        );
+    }
 }